@@ -8,11 +8,13 @@ use ordered_float::OrderedFloat;
 // use std::io::BufReader;
 // use std::fs::File;
 // use std::env;
+use std::sync::Arc;
 use std::vec::Vec;
 
-use room_renovation::flooring::{FlooringBuilder};
+use room_renovation::catalog::FlooringCatalog;
 use room_renovation::house::{House, HouseBuilder};
-use room_renovation::room::{Room, RoomBuilder};
+use room_renovation::parse;
+use room_renovation::room::{BuildError, Room, RoomBuilder};
 
 ///
 /// Compute the area of a room and the cost of
@@ -67,7 +69,7 @@ fn main() {
 
 const ROOM_DATA: &'static str = r#"
 Laundry Room; 8 4 1.95 Laminate
-Kitchen; 20 12 3.87 Tile
+Kitchen; 20 12*1.1 3.87+0.5 Tile
 Storage Room; 16 16 4.39 Birch Wood
 "#;
 
@@ -75,44 +77,26 @@ Storage Room; 16 16 4.39 Birch Wood
 /// Build our example house
 ///
 fn build_house() -> House {
-    // Parse all rooms
-    let mut parsed_rooms: Vec<Room> = ROOM_DATA
-        .lines()
-        .filter(|line| line.len() > 0)
-        .map(|line| {
-            // Split at the semicolon (grab the name first)
-            let line = line.split(";").collect::<Vec<&str>>();
-            let name = line[0];
-
-            // Split everything else by whitespace and collect the tokens
-            // let the_rest = &line[1];
-            let the_rest: Vec<&str> = line[1].split_whitespace().collect();
-
-            // Parse the three f64 numbers
-            let nums: Vec<f64> = the_rest[0..3]
-                .iter()
-                .map(|token| token.parse().unwrap_or(1_f64))
-                .collect();
-            let (length, width) = (nums[0], nums[1]);
-            let unit_cost = nums[2];
-
-            // The flooring name might contain spaces. Combine the remainder of the line.
-            let flooring_name = the_rest.into_iter().skip(3).join(" ");
+    // Rooms that share a flooring type (by name) share one catalog entry
+    // instead of each carrying their own copy.
+    let mut catalog = FlooringCatalog::new();
+
+    // Parse all rooms. The numeric fields are arithmetic expressions
+    // (see `Kitchen` above), not bare floats.
+    let parsed_rooms: Vec<Room> = parse::parse(ROOM_DATA)
+        .unwrap_or_else(|err| panic!("failed to parse room data: {}", err))
+        .into_iter()
+        .map(|parsed| {
+            let flooring = catalog.get_or_register(&parsed.flooring_name, parsed.unit_cost);
 
             RoomBuilder::new()
-                .with_name(name)
-                .with_dimensions(length, width)
-                .with_flooring(
-                    FlooringBuilder::new()
-                        .with_specific_name(&flooring_name)
-                        .with_unit_cost(unit_cost)
-                        .build()
-                        .unwrap(),
-                )
+                .with_name(&parsed.name)
+                .with_dimensions(parsed.length, parsed.width)
+                .with_flooring(flooring)
                 .build()
         })
-        .flatten()
-        .collect();
+        .collect::<Result<Vec<Room>, BuildError>>()
+        .unwrap_or_else(|err| panic!("failed to build room: {}", err));
 
     // Create a house using the parsed rooms
     let house = HouseBuilder::new()
@@ -135,42 +119,17 @@ fn build_house() -> House {
 /// House with the updated flooring
 ///
 fn upgrade_flooring(original: &House) -> House {
-    /*
-    let mut bldr = HouseBuilder::new()
-        .with_name("After Stone Bricks")
-
-    for room in original.iter() {
-        let mut updated_room = room.clone();
-        updated_room.set_flooring("Stone Bricks", 12.97);
-
-        bldr = bldr.with_room(updated_room);
-    }
-    */
-    /*
-    let house = HouseBuilder::new()
-        .with_name("After Stone Bricks")
-        .with_rooms(
-            &mut original.iter()
-            .map(|room| {
-                let mut updated_room = room.clone();
-                updated_room.set_flooring("Stone Bricks", 12.97);
+    // One shared record, no matter how many rooms the house has.
+    let mut catalog = FlooringCatalog::new();
+    let stone_bricks = catalog.register("Stone Bricks", 12.97);
 
-                updated_room
-            })
-            .collect::<Vec<Room>>()
-        )
-        .build()
-        .unwrap();
-
-    house
-    */
     HouseBuilder::new()
         .with_name("After Stone Bricks")
         .with_rooms(
             original.iter()
             .map(|room| {
                 let mut updated_room = room.clone();
-                updated_room.set_flooring("Stone Bricks", 12.97);
+                updated_room.set_flooring(Arc::clone(&stone_bricks));
 
                 updated_room
             })
@@ -0,0 +1,77 @@
+use crate::flooring::Flooring;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+//------------------------------------------------------------------------------
+/// A shared registry of named flooring types.
+///
+/// Rooms hold an `Arc<Flooring>` pointing into a catalog rather than an
+/// owned copy, so many rooms can share one immutable flooring record and a
+/// house built from a catalog is `Send + Sync`. Note that `Flooring` itself
+/// has no interior mutability: calling `register` again for an existing
+/// `type_name` hands back a brand-new `Arc`, so rooms that already hold the
+/// old one keep the old price. Only `Room`s built from a fresh `get`/
+/// `get_or_register` call after the update see the new price.
+#[derive(Clone, Default)]
+pub struct FlooringCatalog {
+    entries: HashMap<String, Arc<Flooring>>,
+}
+
+impl FlooringCatalog {
+    pub fn new() -> Self {
+        FlooringCatalog {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Register (or replace) a flooring type, returning the shared handle.
+    ///
+    /// # Arguments
+    ///  * `type_name` - flooring type name
+    ///  * `unit_cost` - unit cost
+    pub fn register(&mut self, type_name: &str, unit_cost: f64) -> Arc<Flooring> {
+        let entry = Arc::new(Flooring {
+            type_name: type_name.to_string(),
+            unit_cost,
+        });
+        self.entries.insert(type_name.to_string(), Arc::clone(&entry));
+
+        entry
+    }
+
+    /// Look up a previously registered flooring type by name.
+    pub fn get(&self, type_name: &str) -> Option<Arc<Flooring>> {
+        self.entries.get(type_name).cloned()
+    }
+
+    /// Look up `type_name`, registering it at `unit_cost` if it isn't
+    /// already present in the catalog.
+    pub fn get_or_register(&mut self, type_name: &str, unit_cost: f64) -> Arc<Flooring> {
+        match self.get(type_name) {
+            Some(flooring) => flooring,
+            None => self.register(type_name, unit_cost),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rooms_sharing_a_type_name_share_one_arc() {
+        let mut catalog = FlooringCatalog::new();
+
+        let first = catalog.register("Tile", 3.87);
+        let second = catalog.get_or_register("Tile", 3.87);
+
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn get_returns_none_for_an_unregistered_type() {
+        let catalog = FlooringCatalog::new();
+
+        assert!(catalog.get("Tile").is_none());
+    }
+}
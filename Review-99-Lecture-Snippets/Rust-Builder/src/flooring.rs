@@ -0,0 +1,21 @@
+//------------------------------------------------------------------------------
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Flooring {
+    pub type_name: String,
+    pub unit_cost: f64,
+}
+
+impl Default for Flooring {
+    fn default() -> Self {
+        Flooring {
+            type_name: "Generic".to_string(),
+            unit_cost: 0f64,
+        }
+    }
+}
+
+// Rooms whose flooring comes from one `FlooringCatalog` (see `catalog.rs`)
+// share the same `Arc<Flooring>` per type name. The fields are public, so a
+// `Flooring` can also be built directly; such a value is not shared with any
+// catalog entry.
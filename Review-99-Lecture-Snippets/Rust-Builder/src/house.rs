@@ -0,0 +1,161 @@
+use crate::room::{BuildError, Room};
+use std::fmt;
+use std::fmt::Display;
+use std::slice::Iter;
+
+//------------------------------------------------------------------------------
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct House {
+    pub name: String,
+    pub rooms: Vec<Room>,
+}
+
+impl House {
+    /// Iterate over the rooms in the house.
+    pub fn iter(&self) -> Iter<'_, Room> {
+        self.rooms.iter()
+    }
+
+    /// Total flooring cost across every room in the house.
+    pub fn total_cost(&self) -> f64 {
+        self.rooms.iter().map(Room::flooring_cost).sum()
+    }
+
+    /// Deserialize a `House` from a JSON document.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize this `House` to a JSON document.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Deserialize a `House` from a TOML document.
+    #[cfg(feature = "serde")]
+    pub fn from_toml(toml_str: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml_str)
+    }
+
+    /// Serialize this `House` to a TOML document.
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+}
+
+impl Default for House {
+    fn default() -> Self {
+        House {
+            name: "Generic".to_string(),
+            rooms: Vec::new(),
+        }
+    }
+}
+
+impl Display for House {
+    #[allow(unused_must_use)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "House ({})", self.name);
+        for room in self.iter() {
+            write!(f, "{}", room);
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq for House {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.name.eq(&rhs.name) && self.rooms.eq(&rhs.rooms)
+    }
+}
+
+//------------------------------------------------------------------------------
+pub struct HouseBuilder<'a> {
+    name: Option<&'a str>,
+    rooms: Vec<Room>,
+}
+
+impl<'a> Default for HouseBuilder<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> HouseBuilder<'a> {
+    pub fn new() -> Self {
+        HouseBuilder {
+            name: None,
+            rooms: Vec::new(),
+        }
+    }
+
+    /// Set the name using the builder pattern.
+    ///
+    /// # Arguments
+    ///  * `nme` - house name
+    ///
+    pub fn with_name(mut self, nme: &'a str) -> Self {
+        self.name = Some(nme);
+
+        self
+    }
+
+    /// Set the rooms using the builder pattern.
+    ///
+    /// # Arguments
+    ///  * `rms` - the rooms that make up the house
+    ///
+    pub fn with_rooms(mut self, rms: Vec<Room>) -> Self {
+        self.rooms = rms;
+
+        self
+    }
+
+    pub fn build(self) -> Result<House, BuildError> {
+        Ok(House {
+            name: self.name.unwrap_or("Generic").to_owned(),
+            rooms: self.rooms,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+    use super::*;
+    use crate::room::RoomBuilder;
+    use std::sync::Arc;
+
+    #[test]
+    fn json_round_trip_preserves_room_and_flooring_details() {
+        let house = HouseBuilder::new()
+            .with_name("Test House")
+            .with_rooms(vec![RoomBuilder::new()
+                .with_name("Kitchen")
+                .with_dimensions(20.0, 12.0)
+                .with_flooring(Arc::new(crate::flooring::Flooring {
+                    type_name: "Tile".to_string(),
+                    unit_cost: 3.87,
+                }))
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let json = house.to_json().unwrap();
+        let round_tripped = House::from_json(&json).unwrap();
+
+        assert_eq!(round_tripped.name, house.name);
+        assert_eq!(round_tripped.rooms.len(), 1);
+        assert_eq!(round_tripped.rooms[0].name, "Kitchen");
+        assert_eq!(round_tripped.rooms[0].dimensions.length, 20.0);
+        assert_eq!(round_tripped.rooms[0].dimensions.width, 12.0);
+        assert_eq!(round_tripped.rooms[0].flooring.type_name, "Tile");
+        assert_eq!(round_tripped.rooms[0].flooring.unit_cost, 3.87);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod catalog;
+pub mod flooring;
+pub mod house;
+pub mod parse;
+pub mod room;
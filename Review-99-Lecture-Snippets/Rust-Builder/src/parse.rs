@@ -0,0 +1,229 @@
+use crate::room::BuildError;
+
+//------------------------------------------------------------------------------
+//
+// A small PEG-style grammar for room-spec lines:
+//
+//   file          = line+
+//   line          = name ";" expr expr expr flooring_name
+//   name          = (!";" ANY)+
+//   expr          = term (("+" | "-") term)*
+//   term          = factor (("*" | "/") factor)*
+//   factor        = float | "(" expr ")"
+//   flooring_name = ANY*
+//
+// The three numeric fields of a `line` are arithmetic expressions rather
+// than bare floats, so `Kitchen; 20 12*1.1 3.87+0.5 Tile` is valid input.
+//
+//------------------------------------------------------------------------------
+
+/// A single parsed room-spec line, before it is handed to `RoomBuilder`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedRoom {
+    pub name: String,
+    pub length: f64,
+    pub width: f64,
+    pub unit_cost: f64,
+    pub flooring_name: String,
+}
+
+/// Parse every non-blank line of `input` (the `file` rule) into a `ParsedRoom`.
+pub fn parse(input: &str) -> Result<Vec<ParsedRoom>, BuildError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            parse_line(line).map_err(|message| BuildError::ParseError {
+                line: idx + 1,
+                context: format!("{} (in \"{}\")", message, line.trim()),
+            })
+        })
+        .collect()
+}
+
+/// Parse the `line` rule: `name ";" expr expr expr flooring_name`.
+fn parse_line(line: &str) -> Result<ParsedRoom, String> {
+    let mut halves = line.splitn(2, ';');
+    let name = halves.next().unwrap_or("").trim().to_string();
+    let rest = halves
+        .next()
+        .ok_or_else(|| "missing ';' separating name from dimensions".to_string())?;
+
+    let mut cursor = Cursor::new(rest);
+    let length = cursor.parse_expr()?;
+    let width = cursor.parse_expr()?;
+    let unit_cost = cursor.parse_expr()?;
+    let flooring_name = cursor.remainder().trim().to_string();
+
+    Ok(ParsedRoom {
+        name,
+        length,
+        width,
+        unit_cost,
+        flooring_name,
+    })
+}
+
+/// A precedence-climbing cursor over the numeric portion of a `line`,
+/// implementing the `expr`/`term`/`factor` rules.
+struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Cursor { input, pos: 0 }
+    }
+
+    fn remainder(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.remainder().chars().next()
+    }
+
+    /// `expr = term (("+" | "-") term)*`, folding each operator/operand
+    /// pair into the running total left to right.
+    fn parse_expr(&mut self) -> Result<f64, String> {
+        let mut acc = self.parse_term()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('+') => {
+                    self.pos += 1;
+                    acc += self.parse_term()?;
+                }
+                Some('-') => {
+                    self.pos += 1;
+                    acc -= self.parse_term()?;
+                }
+                _ => break,
+            }
+        }
+        Ok(acc)
+    }
+
+    /// `term = factor (("*" | "/") factor)*`
+    fn parse_term(&mut self) -> Result<f64, String> {
+        let mut acc = self.parse_factor()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('*') => {
+                    self.pos += 1;
+                    acc *= self.parse_factor()?;
+                }
+                Some('/') => {
+                    self.pos += 1;
+                    let divisor = self.parse_factor()?;
+                    if divisor == 0.0 {
+                        return Err("division by zero".to_string());
+                    }
+                    acc /= divisor;
+                }
+                _ => break,
+            }
+        }
+        Ok(acc)
+    }
+
+    /// `factor = float | "(" expr ")"`
+    fn parse_factor(&mut self) -> Result<f64, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let value = self.parse_expr()?;
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(')') => {
+                        self.pos += 1;
+                        Ok(value)
+                    }
+                    _ => Err("expected closing ')'".to_string()),
+                }
+            }
+            Some(c) if c == '-' || c == '+' || c.is_ascii_digit() || c == '.' => {
+                self.parse_number()
+            }
+            _ => Err("expected a number or '('".to_string()),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<f64, String> {
+        let start = self.pos;
+        if matches!(self.peek(), Some('-') | Some('+')) {
+            self.pos += 1;
+        }
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        let token = &self.input[start..self.pos];
+        token
+            .parse::<f64>()
+            .map_err(|_| format!("invalid number '{}'", token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_plain_line() {
+        let rooms = parse("Laundry Room; 8 4 1.95 Laminate").unwrap();
+
+        assert_eq!(rooms.len(), 1);
+        assert_eq!(rooms[0].name, "Laundry Room");
+        assert_eq!(rooms[0].length, 8.0);
+        assert_eq!(rooms[0].width, 4.0);
+        assert_eq!(rooms[0].unit_cost, 1.95);
+        assert_eq!(rooms[0].flooring_name, "Laminate");
+    }
+
+    #[test]
+    fn multiplication_binds_tighter_than_addition() {
+        let rooms = parse("Kitchen; 20 12*1.1 3.87+0.5 Tile").unwrap();
+
+        assert!((rooms[0].width - 13.2).abs() < 1e-9);
+        assert!((rooms[0].unit_cost - 4.37).abs() < 1e-9);
+
+        let mixed = parse("Room; 1+2*3 1 1 Tile").unwrap();
+
+        assert!((mixed[0].length - 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parentheses_override_precedence() {
+        let rooms = parse("Room; (1+2)*3 1 1 Tile").unwrap();
+
+        assert!((rooms[0].length - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn division_by_zero_is_an_error() {
+        let err = parse("Room; 1 1/0 1 Tile").unwrap_err();
+
+        match err {
+            BuildError::ParseError { line, context } => {
+                assert_eq!(line, 1);
+                assert!(context.contains("division by zero"));
+            }
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn missing_semicolon_is_an_error() {
+        assert!(parse("Room 8 4 1.95 Laminate").is_err());
+    }
+}
@@ -2,11 +2,24 @@ use crate::flooring::*;
 use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Display; //,Formatter,Result};
+use std::sync::Arc;
 
 //------------------------------------------------------------------------------
 #[derive(Debug)]
-pub enum BuildError<'a> {
-    GenericError(&'a str),
+pub enum BuildError {
+    GenericError(&'static str),
+    /// A `room_renovation::parse` failure, tagged with the 1-based line
+    /// number and a message describing what the parser expected.
+    ParseError { line: usize, context: String },
+    /// A required builder field was never set.
+    MissingField(&'static str),
+    /// A dimension was zero, negative, or NaN.
+    NonPositiveDimension { field: &'static str, value: f64 },
+    /// A unit cost was negative.
+    NegativeCost(f64),
+    /// More than one problem was found; reported together so a caller
+    /// sees every issue instead of one panic at a time.
+    Multiple(Vec<BuildError>),
 }
 
 /*
@@ -17,18 +30,35 @@ impl From<std::io::Error> for ParseError {
 }
 */
 
-impl<'a> fmt::Display for BuildError<'a> {
+impl fmt::Display for BuildError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self {
             BuildError::GenericError(description) => {
                 write!(f, "{:?}", description)
             }
+            BuildError::ParseError { line, context } => {
+                write!(f, "line {}: {}", line, context)
+            }
+            BuildError::MissingField(field) => {
+                write!(f, "missing required field '{}'", field)
+            }
+            BuildError::NonPositiveDimension { field, value } => {
+                write!(f, "'{}' must be a positive number (got {})", field, value)
+            }
+            BuildError::NegativeCost(cost) => {
+                write!(f, "unit cost must not be negative (got {})", cost)
+            }
+            BuildError::Multiple(errors) => {
+                let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "{}", messages.join("; "))
+            }
         }
     }
 }
 //------------------------------------------------------------------------------
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DimensionSet {
     pub length: f64,
     pub width: f64,
@@ -50,11 +80,12 @@ impl Default for DimensionSet {
 }
 
 //------------------------------------------------------------------------------
-#[derive(Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Room {
     pub name: String,
     pub dimensions: DimensionSet,
-    pub flooring: Flooring,
+    pub flooring: Arc<Flooring>,
 }
 
 impl Room {
@@ -72,12 +103,11 @@ impl Room {
     /// Set the Flooring using the builder pattern.
     ///
     /// # Arguments
-    ///  * `nme` - flooring type name
-    ///  * `unit_c` - unit cost
+    ///  * `flooring` - shared flooring record, typically obtained from a
+    ///    `FlooringCatalog`
     ///
-    pub fn with_flooring(mut self, nme: &str, unit_c: f64) -> Self {
-        self.flooring.type_name = nme.to_string();
-        self.flooring.unit_cost = unit_c;
+    pub fn with_flooring(mut self, flooring: Arc<Flooring>) -> Self {
+        self.flooring = flooring;
 
         self
     }
@@ -98,12 +128,11 @@ impl Room {
     /// Set the flooring.
     ///
     /// # Arguments
-    ///  * `nme` - flooring type name
-    ///  * `unit_c` - unit cost
+    ///  * `flooring` - shared flooring record, typically obtained from a
+    ///    `FlooringCatalog`
     ///
-    pub fn set_flooring(&mut self, nme: &str, unit_c: f64) {
-        self.flooring.type_name = nme.to_string();
-        self.flooring.unit_cost = unit_c;
+    pub fn set_flooring(&mut self, flooring: Arc<Flooring>) {
+        self.flooring = flooring;
     }
 
     /// Compute the area of flooring for a room.
@@ -153,39 +182,48 @@ impl PartialOrd for Room {
 
 impl PartialEq for Room {
     fn eq(&self, rhs: &Self) -> bool {
-        return self.name.eq(&rhs.name) && self.area().eq(&rhs.area());
+        self.name.eq(&rhs.name) && self.area().eq(&rhs.area())
     }
 }
 
 //------------------------------------------------------------------------------
-pub struct RoomBuilder<'a> {
-    name: Option<&'a str>,
+pub struct RoomBuilder {
+    name: Option<String>,
     length: Option<f64>,
     width: Option<f64>,
-    flooring_name: Option<&'a str>,
-    unit_cost: Option<f64>,
+    flooring: Option<Arc<Flooring>>,
 }
 
-impl<'a> RoomBuilder<'a> {
+impl Default for RoomBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoomBuilder {
     pub fn new() -> Self {
         RoomBuilder {
             name: None,
             length: None,
             width: None,
-            flooring_name: None,
-            unit_cost: None,
+            flooring: None,
         }
     }
 
-    pub fn with_name(mut self, nme: &'a str) -> Self {
-        self.name = Some(nme);
+    pub fn with_name(mut self, nme: &str) -> Self {
+        self.name = Some(nme.to_string());
 
         self
     }
 
-    pub fn with_flooring(mut self, nme: &'a str, unit_c: f64) -> Self {
-        self.flooring_name = Some(nme);
-        self.unit_cost = Some(unit_c);
+    /// Set the flooring using the builder pattern.
+    ///
+    /// # Arguments
+    ///  * `flooring` - shared flooring record, typically obtained from a
+    ///    `FlooringCatalog`
+    ///
+    pub fn with_flooring(mut self, flooring: Arc<Flooring>) -> Self {
+        self.flooring = Some(flooring);
 
         self
     }
@@ -197,21 +235,56 @@ impl<'a> RoomBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<Room, BuildError<'a>> {
-        if self.name.is_none() {
-            return Err(BuildError::GenericError("Name can not be blank"));
+    pub fn build(self) -> Result<Room, BuildError> {
+        let mut errors: Vec<BuildError> = Vec::new();
+
+        if self.name.as_deref().is_none_or(str::is_empty) {
+            errors.push(BuildError::MissingField("name"));
         }
 
-        let room = Room {
-            name: self.name.unwrap().to_owned(),
-            dimensions: DimensionSet::new(self.length.unwrap(), self.width.unwrap()),
-            flooring: Flooring {
-                type_name: self.flooring_name.unwrap().to_owned(),
-                unit_cost: self.unit_cost.unwrap(),
-            },
-        };
+        match self.length {
+            None => errors.push(BuildError::MissingField("length")),
+            Some(length) if length.is_nan() || length <= 0.0 => {
+                errors.push(BuildError::NonPositiveDimension {
+                    field: "length",
+                    value: length,
+                })
+            }
+            _ => {}
+        }
+
+        match self.width {
+            None => errors.push(BuildError::MissingField("width")),
+            Some(width) if width.is_nan() || width <= 0.0 => {
+                errors.push(BuildError::NonPositiveDimension {
+                    field: "width",
+                    value: width,
+                })
+            }
+            _ => {}
+        }
+
+        match &self.flooring {
+            None => errors.push(BuildError::MissingField("flooring")),
+            Some(flooring) if flooring.unit_cost < 0.0 => {
+                errors.push(BuildError::NegativeCost(flooring.unit_cost))
+            }
+            _ => {}
+        }
+
+        if !errors.is_empty() {
+            return Err(if errors.len() == 1 {
+                errors.remove(0)
+            } else {
+                BuildError::Multiple(errors)
+            });
+        }
 
-        Ok(room)
+        Ok(Room {
+            name: self.name.unwrap(),
+            dimensions: DimensionSet::new(self.length.unwrap(), self.width.unwrap()),
+            flooring: self.flooring.unwrap(),
+        })
     }
 }
 
@@ -220,3 +293,81 @@ impl From<(f64, f64)> for DimensionSet {
         DimensionSet::new(dims.0, dims.1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile() -> Arc<Flooring> {
+        Arc::new(Flooring {
+            type_name: "Tile".to_string(),
+            unit_cost: 3.87,
+        })
+    }
+
+    #[test]
+    fn builds_a_valid_room() {
+        let room = RoomBuilder::new()
+            .with_name("Kitchen")
+            .with_dimensions(20.0, 12.0)
+            .with_flooring(tile())
+            .build()
+            .unwrap();
+
+        assert_eq!(room.name, "Kitchen");
+        assert_eq!(room.area(), 240.0);
+    }
+
+    #[test]
+    fn rejects_a_missing_name() {
+        let err = RoomBuilder::new()
+            .with_dimensions(20.0, 12.0)
+            .with_flooring(tile())
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(err, BuildError::MissingField("name")));
+    }
+
+    #[test]
+    fn rejects_nan_and_non_positive_dimensions() {
+        let err = RoomBuilder::new()
+            .with_name("Kitchen")
+            .with_dimensions(f64::NAN, 0.0)
+            .with_flooring(tile())
+            .build()
+            .unwrap_err();
+
+        match err {
+            BuildError::Multiple(errors) => assert_eq!(errors.len(), 2),
+            other => panic!("expected Multiple, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_a_negative_unit_cost() {
+        let negative = Arc::new(Flooring {
+            type_name: "Tile".to_string(),
+            unit_cost: -1.0,
+        });
+
+        let err = RoomBuilder::new()
+            .with_name("Kitchen")
+            .with_dimensions(20.0, 12.0)
+            .with_flooring(negative)
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(err, BuildError::NegativeCost(cost) if cost == -1.0));
+    }
+
+    #[test]
+    fn accumulates_every_problem_at_once() {
+        let err = RoomBuilder::new().build().unwrap_err();
+
+        match err {
+            BuildError::Multiple(errors) => assert_eq!(errors.len(), 4),
+            other => panic!("expected Multiple, got {:?}", other),
+        }
+    }
+}